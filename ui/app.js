@@ -128,12 +128,32 @@
       case 'interaction':
         addRequest(message.data);
         break;
+      case 'request_started':
+        addRequest(message.data);
+        break;
+      case 'chunk':
+        appendChunk(message);
+        return;
+      case 'request_completed':
+        upsertRequest(message.data);
+        break;
       case 'intercepted':
         handleInterceptedRequest(message.data);
         break;
       case 'status':
         updateStatus(message.data);
         break;
+      case 'replay_progress':
+        if (message.data && message.data.done) {
+          const { total, failed } = message.data;
+          showToast(`Batch replay finished: ${total - failed}/${total} succeeded`, failed ? 'error' : 'success');
+        }
+        break;
+      case 'subscribed':
+        break;
+      case 'error':
+        showToast(`WebSocket: ${message.error}`, 'error');
+        break;
       default:
         console.log('[Replayr] Unknown message type:', message.type);
     }
@@ -191,6 +211,30 @@
     updateBufferCount();
   }
 
+  function upsertRequest(interaction) {
+    const idx = state.requests.findIndex(r => r.id === interaction.id);
+    if (idx === -1) {
+      addRequest(interaction);
+      return;
+    }
+    state.requests[idx] = interaction;
+    renderRequestList();
+    if (state.selectedId === interaction.id) {
+      showRequestDetail(interaction);
+    }
+  }
+
+  // Streams chunks of an in-flight request into its entry as they arrive.
+  function appendChunk(message) {
+    const req = state.requests.find(r => r.id === message.id);
+    if (!req) return;
+    req.response.streaming = true;
+    req.response.chunks.push({ delay_ms: message.delay_ms, data: message.data });
+    if (state.selectedId === req.id) {
+      showRequestDetail(req);
+    }
+  }
+
   function getFilteredRequests() {
     return state.requests;
   }
@@ -250,7 +294,7 @@
           <span class="request-item__path">${req.request.path}</span>
         </div>
         <div class="request-item__meta">
-          <span class="request-item__status ${statusClass}">${status}</span>
+          <span class="request-item__status ${statusClass}">${status || '…'}</span>
           <span class="request-item__time">${formatDuration(req.metadata?.latency_ms)}</span>
           ${isStreaming ? `
             <span class="request-item__streaming">
@@ -352,13 +396,20 @@
   function highlightHeaders(headers) {
     if (!headers) return '<span class="json-null">null</span>';
 
-    return Object.entries(headers)
+    return headerEntries(headers)
       .map(([key, value]) => {
         return `<span class="json-key">${escapeHtml(key)}</span>: <span class="json-string">${escapeHtml(value)}</span>`;
       })
       .join('\n');
   }
 
+  // Repeated headers are stored as arrays; expand them into one entry each.
+  function headerEntries(headers) {
+    return Object.entries(headers).flatMap(([key, value]) =>
+      Array.isArray(value) ? value.map((v) => [key, v]) : [[key, value]]
+    );
+  }
+
   function highlightJSON(data) {
     if (data === null || data === undefined) {
       return '<span class="json-null">null</span>';
@@ -573,7 +624,7 @@
     parts.push(`-X ${req.request.method}`);
 
     if (req.request.headers) {
-      Object.entries(req.request.headers).forEach(([key, value]) => {
+      headerEntries(req.request.headers).forEach(([key, value]) => {
         if (key.toLowerCase() !== 'content-length') {
           parts.push(`-H '${key}: ${value}'`);
         }
@@ -728,6 +779,21 @@
     });
   }
 
+  // Saved presets are offered as `@name` suggestions; the server expands them.
+  async function loadFilterPresets() {
+    const response = await fetch('/api/v1/filters');
+    if (!response.ok) return;
+    const { filters } = await response.json();
+    const list = $('#filterPresets');
+    list.innerHTML = '';
+    Object.entries(filters).forEach(([name, expression]) => {
+      const option = document.createElement('option');
+      option.value = `@${name}`;
+      option.label = expression;
+      list.appendChild(option);
+    });
+  }
+
   // ==========================================================================
   // COPY BUTTONS
   // ==========================================================================
@@ -1032,6 +1098,9 @@
     initThemes();
     initTabs();
     initFilter();
+    loadFilterPresets().catch((err) => {
+      console.warn('[Replayr] Failed loading filter presets', err);
+    });
     initCopyButtons();
     initInterceptControls();
 